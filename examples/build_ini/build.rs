@@ -0,0 +1,7 @@
+fn main() {
+    println!("cargo:rerun-if-changed=translations.ini");
+
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    sl10n::build_translations(&["translations.ini"], &format!("{out_dir}/l10n.rs"))
+        .expect("failed to build translations from translations.ini");
+}
@@ -0,0 +1,14 @@
+use std::collections::HashMap;
+
+include!(concat!(env!("OUT_DIR"), "/l10n.rs"));
+
+fn main() {
+    let msgs = Msgs::new();
+    let greeting = msgs.msg(Msg::Greeting, "en");
+
+    let params = HashMap::from([("name", "Alice")]);
+    let farewell = msgs.dyn_msg(Msg::Farewell, "es", &params);
+
+    assert_eq!(greeting, "Hello!");
+    assert_eq!(farewell, "Adiós, Alice.");
+}
@@ -0,0 +1,298 @@
+//! Proc-macro companion to the `sl10n` crate.
+//!
+//! A plain `macro_rules!` macro can't compare identifiers against each other, so it has no
+//! way to notice that one key is missing a translation another key has. This crate adds that
+//! missing piece: [`define_l10n_checked`] first collects the union of every `$lang` used
+//! anywhere in the invocation and checks each key against it, emitting one `compile_error!`
+//! per absent `(key, lang)` pair, before generating the same enum/struct/impl code as before.
+//! [`define_l10n_unchecked`] shares that same codegen (see [`expand`]) but skips the check.
+//!
+//! `sl10n` re-exports `define_l10n_checked` under the default `define_l10n!` name, and
+//! `define_l10n_unchecked` under its own name, for opting back into the unchecked behavior.
+
+use std::collections::BTreeSet;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use proc_macro_crate::{crate_name, FoundCrate};
+use quote::quote;
+use syn::{
+    braced,
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+    Expr, Ident, Token,
+};
+
+/// Resolves a usable path to the `sl10n` crate from the generated code's call site, so
+/// invocations still work if the caller's `Cargo.toml` renames the `sl10n` dependency.
+///
+/// `FoundCrate::Itself` is deliberately treated the same as "not found": `crate_name` reports
+/// it for *any* target in the `sl10n` package itself (examples, tests, doctests), not just
+/// `sl10n`'s own library source, and those call the macro with `sl10n` available as a normal
+/// external crate name, same as any downstream dependent — `crate::...` would not resolve
+/// there. Nothing in this codebase invokes the macro from within the library itself.
+fn sl10n_crate_path() -> TokenStream2 {
+    match crate_name("sl10n") {
+        Ok(FoundCrate::Name(name)) => {
+            let ident = Ident::new(&name, Span::call_site());
+            quote! { ::#ident }
+        }
+        _ => quote! { ::sl10n },
+    }
+}
+
+struct Entry {
+    lang: Ident,
+    // Any expression, not just a string literal: a `const`, `concat!(...)`, or other
+    // computed `&'static str` all work.
+    value: Expr,
+}
+
+impl Parse for Entry {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let lang: Ident = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let value: Expr = input.parse()?;
+        Ok(Entry { lang, value })
+    }
+}
+
+struct Key {
+    name: Ident,
+    entries: Vec<Entry>,
+}
+
+impl Parse for Key {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: Ident = input.parse()?;
+        input.parse::<Token![=>]>()?;
+        let content;
+        braced!(content in input);
+        let entries = Punctuated::<Entry, Token![,]>::parse_terminated(&content)?
+            .into_iter()
+            .collect();
+        Ok(Key { name, entries })
+    }
+}
+
+struct Input {
+    struct_name: Ident,
+    enum_name: Ident,
+    keys: Vec<Key>,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // The macro has two forms: `StructName, EnumName, Key => {...}, ...` or just
+        // `Key => {...}, ...` (defaulting to `Msgs`/`Msg`). Try the custom-names form on a
+        // fork first so a plain key list doesn't get misparsed.
+        let fork = input.fork();
+        let custom_names: Option<(Ident, Ident)> = (|| {
+            let struct_name: Ident = fork.parse().ok()?;
+            fork.parse::<Token![,]>().ok()?;
+            let enum_name: Ident = fork.parse().ok()?;
+            fork.parse::<Token![,]>().ok()?;
+            Some((struct_name, enum_name))
+        })();
+
+        let (struct_name, enum_name) = if let Some((struct_name, enum_name)) = custom_names {
+            input.parse::<Ident>()?;
+            input.parse::<Token![,]>()?;
+            input.parse::<Ident>()?;
+            input.parse::<Token![,]>()?;
+            (struct_name, enum_name)
+        } else {
+            (
+                Ident::new("Msgs", input.span()),
+                Ident::new("Msg", input.span()),
+            )
+        };
+
+        let keys = Punctuated::<Key, Token![,]>::parse_terminated(input)?
+            .into_iter()
+            .collect();
+
+        Ok(Input {
+            struct_name,
+            enum_name,
+            keys,
+        })
+    }
+}
+
+/// Shared codegen for both [`define_l10n_checked`] and [`define_l10n_unchecked`]. `check`
+/// selects whether missing `(key, lang)` pairs emit a `compile_error!`; the generated
+/// enum/struct/impl is otherwise identical either way, so the two entry points don't drift.
+fn expand(input: Input, check: bool) -> TokenStream2 {
+    let Input {
+        struct_name,
+        enum_name,
+        keys,
+    } = input;
+
+    let mut errors: Vec<TokenStream2> = Vec::new();
+    if check {
+        // Union of every language used anywhere in this invocation.
+        let mut all_langs: BTreeSet<String> = BTreeSet::new();
+        for key in &keys {
+            for entry in &key.entries {
+                all_langs.insert(entry.lang.to_string());
+            }
+        }
+
+        for key in &keys {
+            let have: BTreeSet<String> = key.entries.iter().map(|e| e.lang.to_string()).collect();
+            for lang in &all_langs {
+                if !have.contains(lang) {
+                    let msg = format!(
+                        "sl10n: key `{}` is missing a translation for language `{}`",
+                        key.name, lang
+                    );
+                    errors.push(quote! { compile_error!(#msg); });
+                }
+            }
+        }
+    }
+
+    let sl10n = sl10n_crate_path();
+
+    let key_idents: Vec<&Ident> = keys.iter().map(|k| &k.name).collect();
+    let key_entries = keys.iter().map(|key| {
+        let key_ident = &key.name;
+        let langs: Vec<&Ident> = key.entries.iter().map(|e| &e.lang).collect();
+        let values: Vec<&Expr> = key.entries.iter().map(|e| &e.value).collect();
+        quote! {
+            let mut lang_map = std::collections::HashMap::new();
+            #(lang_map.insert(stringify!(#langs).to_string(), #values);)*
+            messages.insert(#enum_name::#key_ident, lang_map);
+        }
+    });
+
+    quote! {
+        #(#errors)*
+
+        #[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
+        pub enum #enum_name {
+            #(#key_idents,)*
+        }
+
+        impl #enum_name {
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    #(#enum_name::#key_idents => stringify!(#key_idents),)*
+                }
+            }
+        }
+
+        pub struct #struct_name {
+            messages: std::collections::HashMap<#enum_name, std::collections::HashMap<String, &'static str>>,
+            default_lang: Option<String>,
+            bidi_isolation: bool,
+        }
+
+        impl #struct_name {
+            pub fn new() -> Self {
+                let mut messages = std::collections::HashMap::new();
+                #(#key_entries)*
+                #struct_name { messages, default_lang: None, bidi_isolation: false }
+            }
+
+            /// Like [`Self::new`], but falls back to `default_lang` whenever a key has no
+            /// translation for the requested language, instead of returning `""`.
+            pub fn with_default(default_lang: &str) -> Self {
+                let mut msgs = Self::new();
+                msgs.default_lang = Some(default_lang.to_string());
+                msgs
+            }
+
+            /// Wraps each interpolated parameter in Unicode isolation marks (U+2068 / U+2069)
+            /// during substitution, protecting mixed RTL/LTR content from being visually
+            /// scrambled. Off by default for backward compatibility.
+            pub fn with_bidi_isolation(mut self, enabled: bool) -> Self {
+                self.bidi_isolation = enabled;
+                self
+            }
+
+            /// Like [`Self::get_msg_typed`], but takes plain `&str` parameters; each is
+            /// wrapped as [`sl10n::Arg::Str`](Arg::Str) and routed through the same
+            /// substitution logic.
+            pub fn get_msg(&self, key: #enum_name, lang: &str, params: Option<&std::collections::HashMap<&str, &str>>) -> String {
+                let typed: Option<std::collections::HashMap<&str, #sl10n::Arg<'_>>> =
+                    params.map(|p| p.iter().map(|(k, v)| (*k, #sl10n::Arg::Str(v))).collect());
+                self.get_msg_typed(key, lang, typed.as_ref())
+            }
+
+            pub fn msg(&self, key: #enum_name, lang: &str) -> String {
+                self.get_msg(key, lang, None)
+            }
+
+            pub fn dyn_msg(&self, key: #enum_name, lang: &str, params: &std::collections::HashMap<&str, &str>) -> String {
+                self.get_msg(key, lang, Some(params))
+            }
+
+            /// Like [`Self::get_msg`], but accepts typed [`sl10n::Arg`](Arg) parameters
+            /// instead of `&str`, so numbers and other `Display` values don't need to be
+            /// pre-stringified; this is also what plural category selection reads its
+            /// numeric argument from.
+            pub fn get_msg_typed(&self, key: #enum_name, lang: &str, params: Option<&std::collections::HashMap<&str, #sl10n::Arg<'_>>>) -> String {
+                let langs = self.messages.get(&key);
+                let (message, resolved_lang) = if lang == #sl10n::PSEUDO_LOCALE {
+                    let (source_lang, source) = langs
+                        .and_then(|langs| {
+                            self.default_lang.as_deref()
+                                .and_then(|default_lang| langs.get(default_lang).map(|text| (default_lang, *text)))
+                        })
+                        .or_else(|| langs.and_then(#sl10n::__pick_any))
+                        .unwrap_or(("", ""));
+                    (#sl10n::__pseudo_localize(source), source_lang)
+                } else {
+                    let (resolved_lang, text) = langs
+                        .and_then(|langs| langs.get(lang).map(|text| (lang, *text)))
+                        .or_else(|| {
+                            self.default_lang.as_deref().and_then(|default_lang| {
+                                langs.and_then(|langs| langs.get(default_lang).map(|text| (default_lang, *text)))
+                            })
+                        })
+                        .unwrap_or((lang, ""));
+                    (text.to_string(), resolved_lang)
+                };
+
+                let mut result = #sl10n::__resolve_plurals(&message, resolved_lang, params);
+                if let Some(params) = params {
+                    for (k, v) in params {
+                        let formatted = v.to_string();
+                        let replacement = if self.bidi_isolation {
+                            format!("\u{2068}{}\u{2069}", formatted)
+                        } else {
+                            formatted
+                        };
+                        result = result.replace(&format!("{{{}}}", k), &replacement);
+                    }
+                }
+                result
+            }
+
+            pub fn dyn_msg_typed(&self, key: #enum_name, lang: &str, params: &std::collections::HashMap<&str, #sl10n::Arg<'_>>) -> String {
+                self.get_msg_typed(key, lang, Some(params))
+            }
+        }
+    }
+}
+
+/// Proc-macro entry point mirroring `sl10n::define_l10n!`, with compile-time translation
+/// completeness checking. See the crate-level docs for the behavior.
+#[proc_macro]
+pub fn define_l10n_checked(input: TokenStream) -> TokenStream {
+    let parsed = parse_macro_input!(input as Input);
+    expand(parsed, true).into()
+}
+
+/// Proc-macro entry point mirroring `sl10n::define_l10n_unchecked!`: the same codegen as
+/// [`define_l10n_checked`], just without the completeness check, so the two can't drift out
+/// of sync the way a hand-duplicated `macro_rules!` copy could.
+#[proc_macro]
+pub fn define_l10n_unchecked(input: TokenStream) -> TokenStream {
+    let parsed = parse_macro_input!(input as Input);
+    expand(parsed, false).into()
+}
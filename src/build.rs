@@ -0,0 +1,125 @@
+//! Build-time support for loading translations from external files instead of inline
+//! `define_l10n!` literals; see [`build_translations`].
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+
+/// Error produced while parsing or emitting translations in [`build_translations`].
+#[derive(Debug)]
+pub enum BuildError {
+    /// Reading or writing one of the given files failed.
+    Io(String, std::io::Error),
+    /// A line in a translation file could not be parsed as a section header or a
+    /// `lang = text` entry.
+    Parse {
+        file: String,
+        line: usize,
+        message: String,
+    },
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::Io(path, err) => write!(f, "{path}: {err}"),
+            BuildError::Parse {
+                file,
+                line,
+                message,
+            } => write!(f, "{file}:{line}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+/// Parses one or more section-per-key translation files (`[message_key]` headers followed
+/// by indented `lang = "text"` lines) and writes a ready-to-`include!` `define_l10n!`
+/// invocation to `out_file`, so translators can edit plain text files while the generated
+/// enum/struct keep the same compile-time key (and, by default, completeness) safety as an
+/// inline `define_l10n!` call. If the same key appears in more than one file, later files
+/// add to (rather than replace) the languages already collected for it.
+///
+/// Intended for use from `build.rs`:
+///
+/// ```no_run
+/// fn main() {
+///     let out_dir = std::env::var("OUT_DIR").unwrap();
+///     sl10n::build_translations(&["translations.ini"], &format!("{out_dir}/l10n.rs")).unwrap();
+/// }
+/// ```
+///
+/// and then, in your crate:
+///
+/// ```ignore
+/// include!(concat!(env!("OUT_DIR"), "/l10n.rs"));
+/// ```
+pub fn build_translations(paths: &[&str], out_file: &str) -> Result<(), BuildError> {
+    let mut keys: BTreeMap<String, BTreeMap<String, String>> = BTreeMap::new();
+
+    for path in paths {
+        let contents =
+            fs::read_to_string(path).map_err(|e| BuildError::Io((*path).to_string(), e))?;
+        let mut current_key: Option<String> = None;
+
+        for (i, raw_line) in contents.lines().enumerate() {
+            let line_no = i + 1;
+            let line = raw_line.trim();
+            if line.is_empty() || line.starts_with(';') || line.starts_with('#') {
+                continue;
+            }
+
+            if line.starts_with('[') {
+                let name = line.strip_prefix('[').and_then(|s| s.strip_suffix(']'));
+                let Some(name) = name else {
+                    return Err(BuildError::Parse {
+                        file: (*path).to_string(),
+                        line: line_no,
+                        message: format!("malformed section header `{line}`"),
+                    });
+                };
+                let key = name.trim().to_string();
+                keys.entry(key.clone()).or_default();
+                current_key = Some(key);
+                continue;
+            }
+
+            let Some(key) = current_key.as_ref() else {
+                return Err(BuildError::Parse {
+                    file: (*path).to_string(),
+                    line: line_no,
+                    message: format!("entry `{line}` outside of any `[message_key]` section"),
+                });
+            };
+            let Some((lang, text)) = line.split_once('=') else {
+                return Err(BuildError::Parse {
+                    file: (*path).to_string(),
+                    line: line_no,
+                    message: format!("expected `lang = \"text\"`, found `{line}`"),
+                });
+            };
+
+            let lang = lang.trim().to_string();
+            let text = text.trim();
+            let text = text
+                .strip_prefix('"')
+                .and_then(|s| s.strip_suffix('"'))
+                .unwrap_or(text)
+                .to_string();
+            keys.get_mut(key).unwrap().insert(lang, text);
+        }
+    }
+
+    let mut output = String::from("sl10n::define_l10n! {\n");
+    for (key, langs) in &keys {
+        output.push_str(&format!("    {key} => {{\n"));
+        for (lang, text) in langs {
+            output.push_str(&format!("        {lang}: {text:?},\n"));
+        }
+        output.push_str("    },\n");
+    }
+    output.push_str("}\n");
+
+    fs::write(out_file, output).map_err(|e| BuildError::Io(out_file.to_string(), e))
+}
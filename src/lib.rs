@@ -112,6 +112,117 @@
 /// // "¡Hola, Alice!"
 /// ```
 ///
+/// ## Fallback language
+///
+/// `Msgs::new()` returns `""` when a key has no translation for the requested language. Use
+/// `Msgs::with_default` instead to fall back to a default language in that case:
+///
+/// ```rust
+/// use sl10n::define_l10n;
+///
+/// define_l10n! {
+///     Greeting => {
+///         en: "Hello!",
+///         ru: "Привет!"
+///     }
+/// }
+///
+/// let msgs = Msgs::with_default("en");
+/// let msg = msgs.msg(Msg::Greeting, "es"); // falls back to "en": "Hello!"
+/// ```
+///
+/// ## Plural messages
+///
+/// A message value may contain a `{var -> [cat] text [cat] text ...}` construct to pick
+/// text based on the CLDR plural category of `var`'s numeric value in the current language
+/// (see [`plural_category`]); the chosen branch still gets ordinary `{name}` substitution:
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use sl10n::define_l10n;
+///
+/// define_l10n! {
+///     FileCount => {
+///         en: "{count} {count -> [one] file [other] files}"
+///     }
+/// }
+///
+/// let msgs = Msgs::new();
+/// let mut params = HashMap::new();
+/// params.insert("count", "1");
+/// let msg = msgs.dyn_msg(Msg::FileCount, "en", &params);
+/// assert_eq!(msg, "1 file");
+/// ```
+///
+/// ## Pseudo-localization
+///
+/// Requesting the reserved language code [`PSEUDO_LOCALE`] (`"xx-pseudo"`) instead of a real
+/// `lang` makes `get_msg` return a pseudo-localized message built from the best real
+/// translation available (preferring `default_lang`): non-placeholder letters are swapped
+/// for accented look-alikes and the text is padded with `⟦…⟧` markers, so truncation and
+/// hardcoded strings stand out while testing UI layouts. `{name}` placeholders still
+/// substitute normally:
+///
+/// ```rust
+/// use sl10n::{define_l10n, PSEUDO_LOCALE};
+///
+/// define_l10n! {
+///     Greeting => {
+///         en: "Hello!"
+///     }
+/// }
+///
+/// let msgs = Msgs::new();
+/// let msg = msgs.msg(Msg::Greeting, PSEUDO_LOCALE);
+/// // "⟦Héllö!…⟧"-ish: longer than the original, with accented look-alikes.
+/// assert!(msg.len() > "Hello!".len());
+/// ```
+///
+/// ## Isolating interpolated parameters for mixed RTL/LTR content
+///
+/// `.replace("{name}", v)`-style substitution can visually scramble mixed-direction text.
+/// Opt into wrapping each interpolated value in Unicode isolation marks (First Strong
+/// Isolate / Pop Directional Isolate) with `.with_bidi_isolation(true)`:
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use sl10n::define_l10n;
+///
+/// define_l10n! {
+///     Greeting => {
+///         en: "Hello, {name}!"
+///     }
+/// }
+///
+/// let msgs = Msgs::new().with_bidi_isolation(true);
+/// let params = HashMap::from([("name", "Alice")]);
+/// let msg = msgs.dyn_msg(Msg::Greeting, "en", &params);
+/// assert_eq!(msg, "Hello, \u{2068}Alice\u{2069}!");
+/// ```
+///
+/// ## Typed parameters
+///
+/// `dyn_msg`/`get_msg` require `HashMap<&str, &str>`, forcing callers to pre-stringify
+/// numbers and other values. `dyn_msg_typed`/`get_msg_typed` accept [`Arg`] instead, which
+/// formats itself during substitution and, for `Arg::Int`/`Arg::Float`, feeds plural
+/// category selection directly instead of being re-parsed from text:
+///
+/// ```rust
+/// use sl10n::{define_l10n, Arg};
+/// use std::collections::HashMap;
+///
+/// define_l10n! {
+///     FileCount => {
+///         en: "{count} {count -> [one] file [other] files}"
+///     }
+/// }
+///
+/// let msgs = Msgs::new();
+/// let params = HashMap::from([("count", Arg::Int(5))]);
+/// let msg = msgs.dyn_msg_typed(Msg::FileCount, "en", &params);
+/// assert_eq!(msg, "5 files");
+/// ```
+///
 /// ## Separating messages by modules
 ///
 /// ```rust
@@ -149,69 +260,279 @@
 /// ```
 /// See `./examples/modules/` for a complete code example.
 ///
+/// ## Loading translations from external files
 ///
-#[macro_export]
-macro_rules! define_l10n {
-    ($struct_name:ident, $enum_name:ident, $($key:ident => {$($lang:ident: $value:expr),*}),* $(,)?) => {
-        #[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
-        pub enum $enum_name {
-            $($key,)*
-        }
+/// Keeping every message inline doesn't scale past a few dozen of them, and it keeps
+/// translators from editing strings without touching Rust. [`build_translations`] parses a
+/// section-per-key file from `build.rs` and writes a file with a ready-to-`include!`
+/// `define_l10n!` invocation, so the enum/struct are still generated the same way. See
+/// `./examples/build_ini/` for a complete code example.
+///
+/// ## Compile-time completeness checking
+///
+/// By default `define_l10n!` also checks that every key provides a translation for every
+/// language used anywhere in the invocation. If a key is missing a language that some other
+/// key in the same invocation has, the crate fails to compile with one `compile_error!` per
+/// missing `(key, lang)` pair, e.g.:
+///
+/// ```text
+/// error: sl10n: key `Farewell` is missing a translation for language `es`
+/// ```
+///
+/// This check needs real identifier comparison, which `macro_rules!` can't do on its own, so
+/// `define_l10n!` itself is implemented by the companion `sl10n-macros` proc-macro crate. If
+/// you need the old, unchecked behavior (for example while temporarily letting a translation
+/// lag behind), use [`define_l10n_unchecked!`] instead; it accepts the exact same syntax and
+/// shares the same codegen, just without the `compile_error!`s.
+///
+#[doc(inline)]
+pub use sl10n_macros::define_l10n_checked as define_l10n;
 
-        impl $enum_name {
-            pub fn as_str(&self) -> &'static str {
-                match self {
-                    $(
-                        $enum_name::$key => stringify!($key),
-                    )*
-                }
-            }
+mod build;
+pub use build::{build_translations, BuildError};
+
+/// Reserved language code that [`get_msg`](struct.Msgs.html#method.get_msg) recognizes to
+/// return a pseudo-localized message instead of a real translation, for catching truncation
+/// and hardcoded-string bugs in UI layouts. See the crate docs' "Pseudo-localization" section.
+pub const PSEUDO_LOCALE: &str = "xx-pseudo";
+
+/// A typed interpolation value for [`get_msg_typed`](struct.Msgs.html#method.get_msg_typed)/
+/// [`dyn_msg_typed`](struct.Msgs.html#method.dyn_msg_typed), so callers don't have to
+/// pre-stringify numbers before substituting them into a message. Numeric variants are also
+/// used directly for plural category selection instead of being parsed back out of text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Arg<'a> {
+    Int(i64),
+    Float(f64),
+    Str(&'a str),
+}
+
+impl<'a> Arg<'a> {
+    fn as_i64(&self) -> Option<i64> {
+        match self {
+            Arg::Int(v) => Some(*v),
+            Arg::Float(v) => Some(*v as i64),
+            Arg::Str(v) => v.parse().ok(),
         }
+    }
+}
 
-        pub struct $struct_name {
-            messages: std::collections::HashMap<$enum_name, std::collections::HashMap<String, &'static str>>,
+impl<'a> std::fmt::Display for Arg<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Arg::Int(v) => write!(f, "{v}"),
+            Arg::Float(v) => write!(f, "{v}"),
+            Arg::Str(v) => write!(f, "{v}"),
         }
+    }
+}
 
-        impl $struct_name {
-            pub fn new() -> Self {
-                let mut messages = std::collections::HashMap::new();
-                $(
-                    let mut lang_map = std::collections::HashMap::new();
-                    $(
-                        lang_map.insert(stringify!($lang).to_string(), $value);
-                    )*
-                    messages.insert($enum_name::$key, lang_map);
-                )*
-                $struct_name { messages }
-            }
+impl<'a> From<i64> for Arg<'a> {
+    fn from(v: i64) -> Self {
+        Arg::Int(v)
+    }
+}
 
-            pub fn get_msg(&self, key: $enum_name, lang: &str, params: Option<&std::collections::HashMap<&str, &str>>) -> String {
-                let message = self.messages.get(&key)
-                    .and_then(|langs| langs.get(lang))
-                    .copied()
-                    .unwrap_or("");
-
-                let mut result = message.to_string();
-				if let Some(params) = params {
-					for (k, v) in params {
-						result = result.replace(&format!("{{{}}}", k), v);
-					}
-				}
-                result
-            }
+impl<'a> From<f64> for Arg<'a> {
+    fn from(v: f64) -> Self {
+        Arg::Float(v)
+    }
+}
+
+impl<'a> From<&'a str> for Arg<'a> {
+    fn from(v: &'a str) -> Self {
+        Arg::Str(v)
+    }
+}
 
-            pub fn msg(&self, key: $enum_name, lang: &str) -> String {
-				self.get_msg(key, lang, None)
+/// Returns the CLDR plural category (`"zero"`, `"one"`, `"two"`, `"few"`, `"many"`, or
+/// `"other"`) to use for `n` items in `lang`. This picks the branch of a
+/// `{var -> [cat] text ...}` message body (see [`define_l10n!`]'s docs on plural messages).
+///
+/// English and most Western languages use `"one"` for `n == 1` and `"other"` otherwise; that
+/// rule is also the default for languages this function doesn't special-case. Russian
+/// (`"ru"`, the only special-cased code) additionally distinguishes `"few"` and `"many"`
+/// based on the last one or two digits of `n`.
+pub fn plural_category(lang: &str, n: i64) -> &'static str {
+    match lang {
+        "ru" => {
+            let m10 = n % 10;
+            let m100 = n % 100;
+            if m10 == 1 && m100 != 11 {
+                "one"
+            } else if (2..=4).contains(&m10) && !(12..=14).contains(&m100) {
+                "few"
+            } else {
+                "many"
+            }
+        }
+        _ => {
+            if n == 1 {
+                "one"
+            } else {
+                "other"
             }
+        }
+    }
+}
 
-            pub fn dyn_msg(&self, key: $enum_name, lang: &str, params: &std::collections::HashMap<&str, &str>) -> String {
-				self.get_msg(key, lang, Some(params))
+/// Expands `{var -> [cat] text ...}` plural constructs in `template` into the branch text
+/// matching `var`'s value (looked up in `params` and classified via [`plural_category`]),
+/// leaving ordinary `{name}` placeholders untouched for the caller's own substitution pass.
+/// `lang` must be the language `template` was actually resolved from (which, via
+/// `default_lang` fallback or [`PSEUDO_LOCALE`], can differ from the language the caller
+/// requested), since that's the language whose plural rule applies to the text. Not part of
+/// the public API; called by the code `define_l10n!` generates.
+#[doc(hidden)]
+pub fn __resolve_plurals(
+    template: &str,
+    lang: &str,
+    params: Option<&std::collections::HashMap<&str, Arg<'_>>>,
+) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+    loop {
+        let Some(pos) = rest.find('{') else {
+            result.push_str(rest);
+            break;
+        };
+        result.push_str(&rest[..pos]);
+        let Some(close) = __find_matching_brace(rest.as_bytes(), pos) else {
+            result.push_str(&rest[pos..]);
+            break;
+        };
+        let inner = &rest[pos + 1..close];
+        if let Some(arrow) = inner.find("->") {
+            let var = inner[..arrow].trim();
+            let n = params
+                .and_then(|p| p.get(var))
+                .and_then(|v| v.as_i64())
+                .unwrap_or(0);
+            let category = plural_category(lang, n);
+            result.push_str(&__pick_plural_branch(&inner[arrow + 2..], category));
+        } else {
+            result.push_str(&rest[pos..=close]);
+        }
+        rest = &rest[close + 1..];
+    }
+    result
+}
+
+fn __find_matching_brace(bytes: &[u8], open_idx: usize) -> Option<usize> {
+    let mut depth = 0i32;
+    for (i, &b) in bytes.iter().enumerate().skip(open_idx) {
+        match b {
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
             }
+            _ => {}
         }
-    };
+    }
+    None
+}
+
+fn __pick_plural_branch(branches_src: &str, category: &str) -> String {
+    let mut branches: Vec<(&str, &str)> = Vec::new();
+    let mut rest = branches_src;
+    while let Some(start) = rest.find('[') {
+        let after_bracket = &rest[start + 1..];
+        let Some(end) = after_bracket.find(']') else {
+            break;
+        };
+        let cat = after_bracket[..end].trim();
+        let remaining = &after_bracket[end + 1..];
+        let next_bracket = remaining.find('[').unwrap_or(remaining.len());
+        branches.push((cat, remaining[..next_bracket].trim()));
+        rest = &remaining[next_bracket..];
+    }
+    branches
+        .iter()
+        .find(|(cat, _)| *cat == category)
+        .or_else(|| branches.iter().find(|(cat, _)| *cat == "other"))
+        .map(|(_, text)| text.to_string())
+        .unwrap_or_default()
+}
 
-	// Version with default names - Msgs and Msg
-    ($($key:ident => {$($lang:ident: $value:expr),*}),* $(,)?) => {
-        define_l10n!(Msgs, Msg, $($key => {$($lang: $value),*}),*);
-    };
+/// Picks one `(lang, text)` pair out of a key's language map, deterministically (by lang
+/// code), for use as a pseudo-localization source when no `default_lang` is set. The lang
+/// code is returned alongside the text so callers can select the right plural rule for it.
+#[doc(hidden)]
+pub fn __pick_any<'a>(langs: &'a std::collections::HashMap<String, &'static str>) -> Option<(&'a str, &'static str)> {
+    let mut codes: Vec<&String> = langs.keys().collect();
+    codes.sort();
+    codes.into_iter().next().map(|lang| (lang.as_str(), langs[lang]))
 }
+
+/// Transforms `text` into its pseudo-localized form: non-placeholder ASCII vowels and a few
+/// consonants (n, c, s, z, g) are mapped to accented look-alikes and the result is padded to roughly 140% of the original
+/// length with `⟦…⟧` bracket markers, so truncation and hardcoded strings stand out in a UI.
+/// Text inside `{...}` placeholders (including plural constructs) is passed through
+/// untouched so parameter substitution still works. Not part of the public API; called by
+/// the code `define_l10n!` generates when [`PSEUDO_LOCALE`] is requested.
+#[doc(hidden)]
+pub fn __pseudo_localize(text: &str) -> String {
+    let mut transformed = String::with_capacity(text.len());
+    let mut rest = text;
+    loop {
+        let Some(pos) = rest.find('{') else {
+            transformed.push_str(&__pseudo_localize_chars(rest));
+            break;
+        };
+        transformed.push_str(&__pseudo_localize_chars(&rest[..pos]));
+        let Some(close) = __find_matching_brace(rest.as_bytes(), pos) else {
+            transformed.push_str(&rest[pos..]);
+            break;
+        };
+        transformed.push_str(&rest[pos..=close]);
+        rest = &rest[close + 1..];
+    }
+
+    let target_len = (text.chars().count() as f64 * 1.4).ceil() as usize;
+    let wrapped_len = transformed.chars().count() + 2; // for the surrounding ⟦ ⟧
+    let filler: String = "…".repeat(target_len.saturating_sub(wrapped_len));
+    format!("⟦{transformed}{filler}⟧")
+}
+
+fn __pseudo_localize_chars(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            'a' => 'á',
+            'A' => 'Á',
+            'e' => 'é',
+            'E' => 'É',
+            'i' => 'í',
+            'I' => 'Í',
+            'o' => 'ö',
+            'O' => 'Ö',
+            'u' => 'ú',
+            'U' => 'Ú',
+            'y' => 'ý',
+            'Y' => 'Ý',
+            'n' => 'ñ',
+            'N' => 'Ñ',
+            'c' => 'ç',
+            'C' => 'Ç',
+            's' => 'š',
+            'S' => 'Š',
+            'z' => 'ž',
+            'Z' => 'Ž',
+            'g' => 'ĝ',
+            'G' => 'Ĝ',
+            other => other,
+        })
+        .collect()
+}
+
+/// Unchecked version of [`define_l10n!`] kept for backward compatibility and as an opt-out
+/// from the compile-time translation completeness check: it accepts any set of languages per
+/// message key without comparing keys against each other, so a missing translation silently
+/// resolves at runtime (to the `default_lang` translation if one is set, otherwise to `""`)
+/// instead of failing to compile.
+/// Generates the exact same enum/struct/impl code as [`define_l10n!`] (the `sl10n-macros`
+/// crate shares one codegen path between the two), just without the completeness check.
+#[doc(inline)]
+pub use sl10n_macros::define_l10n_unchecked;
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use super::*;
-	use sl10n::define_l10n;
+	use sl10n::{define_l10n, define_l10n_unchecked, Arg, PSEUDO_LOCALE};
 	use std::collections::HashMap;
 
 	define_l10n! {
@@ -16,6 +16,10 @@ mod tests {
 		Farewell => {
 			en: "Goodbye, {name}.",
 			ru: "Пока, {name}."
+		},
+		FileCount => {
+			en: "{count} {count -> [one] file [other] files}",
+			ru: "{count} {count -> [one] файл [few] файла [many] файлов [other] файла}"
 		}
 	}
 
@@ -53,4 +57,104 @@ mod tests {
 		let msg_ru = msgs.msg(CustomMsg::Continue, "en");
 		assert_eq!(msg_ru, "Continue");
 	}
+
+	#[test]
+	pub fn fallback_to_default_lang() {
+		// The key has no "es" translation. define_l10n_unchecked! makes that explicit; the
+		// completeness check would not catch it anyway, since it only compares keys against
+		// languages used elsewhere in the same invocation and "es" appears nowhere here.
+		define_l10n_unchecked! {
+			FallbackMsgs, // struct name
+			FallbackMsg, // enum name
+			OnlyEnglish => {
+				en: "Only in English"
+			},
+		}
+
+		let msgs = FallbackMsgs::with_default("en");
+		assert_eq!(msgs.msg(FallbackMsg::OnlyEnglish, "es"), "Only in English");
+
+		let msgs_no_default = FallbackMsgs::new();
+		assert_eq!(msgs_no_default.msg(FallbackMsg::OnlyEnglish, "es"), "");
+	}
+
+	#[test]
+	pub fn plural_messages() {
+		let msgs = Msgs::new();
+
+		let mut params = HashMap::new();
+		params.insert("count", "1");
+		assert_eq!(msgs.dyn_msg(Msg::FileCount, "en", &params), "1 file");
+
+		params.insert("count", "5");
+		assert_eq!(msgs.dyn_msg(Msg::FileCount, "en", &params), "5 files");
+
+		params.insert("count", "3");
+		assert_eq!(msgs.dyn_msg(Msg::FileCount, "ru", &params), "3 файла");
+	}
+
+	#[test]
+	pub fn plural_category_follows_resolved_language() {
+		// This key is intentionally missing an "en" translation so that requesting "en"
+		// exercises the default_lang fallback ("en" appears nowhere in this invocation, so the
+		// completeness check would not flag it either).
+		define_l10n_unchecked! {
+			FallbackPluralMsgs, // struct name
+			FallbackPluralMsg, // enum name
+			FileCount => {
+				ru: "{count} {count -> [one] файл [few] файла [many] файлов [other] файла}"
+			},
+		}
+
+		// `ru` has no `en` translation, so `with_default("ru")` resolves the Russian text even
+		// when "en" is requested. The plural category must follow `ru`'s rule (11 -> "many"),
+		// not "en"'s rule (11 -> "other"), since that's the language of the text that's shown.
+		let msgs = FallbackPluralMsgs::with_default("ru");
+		let mut params = HashMap::new();
+		params.insert("count", "11");
+		assert_eq!(
+			msgs.dyn_msg(FallbackPluralMsg::FileCount, "en", &params),
+			"11 файлов"
+		);
+	}
+
+	#[test]
+	pub fn pseudo_localization() {
+		let msgs = Msgs::new();
+
+		let msg = msgs.msg(Msg::Continue, PSEUDO_LOCALE);
+		assert!(msg.starts_with('⟦'));
+		assert!(msg.ends_with('⟧'));
+		assert!(msg.len() > "Continue".len());
+
+		let mut params = HashMap::new();
+		params.insert("name", "Alice");
+		let msg = msgs.dyn_msg(Msg::Farewell, PSEUDO_LOCALE, &params);
+		assert!(msg.contains("Alice"));
+	}
+
+	#[test]
+	pub fn bidi_isolation() {
+		let msgs = Msgs::new().with_bidi_isolation(true);
+
+		let mut params = HashMap::new();
+		params.insert("name", "Alice");
+		let msg = msgs.dyn_msg(Msg::Farewell, "en", &params);
+		assert_eq!(msg, "Goodbye, \u{2068}Alice\u{2069}.");
+
+		let msgs_off = Msgs::new();
+		let msg_off = msgs_off.dyn_msg(Msg::Farewell, "en", &params);
+		assert_eq!(msg_off, "Goodbye, Alice.");
+	}
+
+	#[test]
+	pub fn typed_params() {
+		let msgs = Msgs::new();
+
+		let params = HashMap::from([("count", Arg::Int(1))]);
+		assert_eq!(msgs.dyn_msg_typed(Msg::FileCount, "en", &params), "1 file");
+
+		let params = HashMap::from([("count", Arg::Int(5))]);
+		assert_eq!(msgs.dyn_msg_typed(Msg::FileCount, "en", &params), "5 files");
+	}
 }
@@ -0,0 +1,9 @@
+//! Compile-fail coverage for `define_l10n!`'s completeness check: without this, nothing
+//! actually exercises the `compile_error!` path (every other test either passes complete
+//! translations to `define_l10n!` or uses `define_l10n_unchecked!`).
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/missing_translation.rs");
+}
@@ -0,0 +1,13 @@
+use sl10n::define_l10n;
+
+define_l10n! {
+    Greeting => {
+        en: "Hello!",
+        ru: "Привет!"
+    },
+    Farewell => {
+        en: "Goodbye."
+    }
+}
+
+fn main() {}